@@ -1,7 +1,7 @@
 use crate::{Node, RenderContext};
 use async_trait::async_trait;
 use std::any::Any;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use wasm_bindgen::UnwrapThrowExt;
 
@@ -175,8 +175,216 @@ impl<'a> AsyncRender<'a>
     }
 }
 
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// The part of a [`Suspense`] that must outlive a single render pass: the
+/// in-flight future and its cached, resolved value.
+///
+/// This is deliberately free of any bump-arena lifetime (it is `'static`
+/// whenever `T` is), so (unlike `Suspense` itself) it can live inside a
+/// user's persistent root model instead of being tied to one
+/// `RenderContext<'a>`. Build one `SuspenseState` up front and store it in
+/// your model; then build a fresh, thin `Suspense` wrapper around a
+/// reference to it inside `Render::render` on every pass — see the
+/// [`Suspense`] docs for the full pattern.
+pub struct SuspenseState<T> {
+    future: RefCell<Option<Pin<Box<dyn Future<Output = T> + 'static>>>>,
+    resolved: RefCell<Option<T>>,
+}
+
+impl<T> SuspenseState<T> {
+    /// Create a new `SuspenseState` that will poll `future` to completion.
+    pub fn new(future: impl Future<Output = T> + 'static) -> SuspenseState<T> {
+        SuspenseState {
+            future: RefCell::new(Some(Box::pin(future))),
+            resolved: RefCell::new(None),
+        }
+    }
+
+    /// Poll the inner future, unless it has already resolved, calling
+    /// `schedule_render` the first time (and only the first time) the
+    /// resulting waker is woken. Returns `true` if a resolved value is
+    /// cached, either from a previous call or from this one.
+    fn poll(&self, schedule_render: Rc<dyn Fn()>) -> bool {
+        if self.resolved.borrow().is_some() {
+            return true;
+        }
+
+        let mut slot = self.future.borrow_mut();
+        let future = match slot.as_mut() {
+            Some(future) => future,
+            // `future` is only ever cleared in the same statement
+            // sequence that fills in `resolved`, so the check above
+            // already returned before we get here.
+            None => unreachable!("SuspenseState future polled again after it resolved"),
+        };
+
+        let waker = suspense_waker(Rc::new(SuspenseWaker {
+            schedule_render,
+            scheduled: Cell::new(false),
+        }));
+        let mut task_cx = Context::from_waker(&waker);
+
+        match future.as_mut().poll(&mut task_cx) {
+            Poll::Pending => false,
+            Poll::Ready(value) => {
+                *slot = None;
+                drop(slot);
+                *self.resolved.borrow_mut() = Some(value);
+                true
+            }
+        }
+    }
+
+    /// The cached resolved value, if the future has completed yet.
+    pub fn resolved(&self) -> std::cell::Ref<'_, Option<T>> {
+        self.resolved.borrow()
+    }
+}
+
+/// A render component that shows a synchronous `fallback` while the
+/// asynchronous computation behind a [`SuspenseState`] is still in flight,
+/// then swaps in the real content once that computation resolves.
+///
+/// Unlike [`AsyncRender`], `Suspense` never blocks the render pass: each
+/// call to [`Render::render`] polls the underlying `SuspenseState` exactly
+/// once, and if it is not ready yet, the `fallback` subtree is returned
+/// immediately so diffing and patching can proceed.
+///
+/// `Suspense` only borrows its `SuspenseState` (and owns a `'a`-scoped
+/// `fallback` and `render_resolved`), so it must be rebuilt fresh on every
+/// render pass, exactly like `render_model` does in the example below — the state it
+/// borrows, not the wrapper itself, is what carries the in-flight future
+/// and resolved value forward between passes. When the future resolves,
+/// its output is cached on the `SuspenseState` and handed to
+/// `render_resolved` for that pass and every following one, and a
+/// re-render of the root component is scheduled so the fallback is
+/// swapped out for the real content.
+///
+/// ## Example
+///
+/// ```no_run
+/// use dodrio::{Node, Render, RenderContext, Suspense, SuspenseState, VdomWeak};
+///
+/// struct MyModel {
+///     name: SuspenseState<String>,
+/// }
+///
+/// fn render_name<'a>(name: &String, cx: &mut RenderContext<'a>) -> Node<'a> {
+///     use dodrio::builder::*;
+///     use dodrio::bumpalo::collections::String as BumpString;
+///
+///     let name = BumpString::from_str_in(name, cx.bump).into_bump_str();
+///     p(&cx).children([text(name)]).finish()
+/// }
+///
+/// fn render_model<'a>(model: &MyModel, vdom: VdomWeak, cx: &mut RenderContext<'a>) -> Node<'a> {
+///     use dodrio::builder::*;
+///
+///     let fallback = p(&cx).children([text("Loading...")]).finish();
+///     Suspense::new(
+///         move || vdom.schedule_render(),
+///         &model.name,
+///         fallback,
+///         render_name,
+///     )
+///     .render(cx)
+/// }
+/// ```
+pub struct Suspense<'a, 'state, T> {
+    schedule_render: Rc<dyn Fn()>,
+    state: &'state SuspenseState<T>,
+    fallback: Node<'a>,
+    render_resolved: Box<dyn Fn(&T, &mut RenderContext<'a>) -> Node<'a> + 'a>,
+}
+
+impl<'a, 'state, T> Suspense<'a, 'state, T> {
+    /// Create a new `Suspense` that renders `fallback` until `state`'s
+    /// future resolves, and `render_resolved` afterwards.
+    ///
+    /// `schedule_render` is called (at most once per render pass) to
+    /// schedule a re-render of the root component once the future
+    /// resolves; pass e.g. `move || vdom.schedule_render()` for a captured
+    /// `VdomWeak`, the same way event listeners schedule redraws.
+    pub fn new(
+        schedule_render: impl Fn() + 'static,
+        state: &'state SuspenseState<T>,
+        fallback: Node<'a>,
+        render_resolved: impl Fn(&T, &mut RenderContext<'a>) -> Node<'a> + 'a,
+    ) -> Suspense<'a, 'state, T> {
+        Suspense {
+            schedule_render: Rc::new(schedule_render),
+            state,
+            fallback,
+            render_resolved: Box::new(render_resolved),
+        }
+    }
+
+    /// Poll the underlying `SuspenseState`, returning `true` if it has a
+    /// resolved value cached (either already, or as of this call).
+    fn is_ready(&self) -> bool {
+        self.state.poll(self.schedule_render.clone())
+    }
+}
+
+impl<'a, 'state, T> Render<'a> for Suspense<'a, 'state, T> {
+    fn render(&self, cx: &mut RenderContext<'a>) -> Node<'a> {
+        if self.is_ready() {
+            let resolved = self.state.resolved();
+            (self.render_resolved)(resolved.as_ref().unwrap(), cx)
+        } else {
+            self.fallback
+        }
+    }
+}
+
+/// State shared by the clones of a single poll's `Waker`, so that however
+/// many times it is woken, only the first wake calls `schedule_render`.
+struct SuspenseWaker {
+    schedule_render: Rc<dyn Fn()>,
+    scheduled: Cell<bool>,
+}
+
+fn suspense_waker(state: Rc<SuspenseWaker>) -> Waker {
+    unsafe fn clone(data: *const ()) -> RawWaker {
+        let state = Rc::from_raw(data as *const SuspenseWaker);
+        let cloned = state.clone();
+        std::mem::forget(state);
+        RawWaker::new(Rc::into_raw(cloned) as *const (), &VTABLE)
+    }
+
+    unsafe fn wake(data: *const ()) {
+        let state = Rc::from_raw(data as *const SuspenseWaker);
+        schedule(&state);
+    }
+
+    unsafe fn wake_by_ref(data: *const ()) {
+        let state = Rc::from_raw(data as *const SuspenseWaker);
+        schedule(&state);
+        std::mem::forget(state);
+    }
+
+    unsafe fn drop_waker(data: *const ()) {
+        drop(Rc::from_raw(data as *const SuspenseWaker));
+    }
+
+    fn schedule(state: &Rc<SuspenseWaker>) {
+        if !state.scheduled.replace(true) {
+            (state.schedule_render)();
+        }
+    }
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+    let raw = RawWaker::new(Rc::into_raw(state) as *const (), &VTABLE);
+    unsafe { Waker::from_raw(raw) }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
     #[test]
     fn render_is_object_safe() {
         #[allow(dead_code)]
@@ -189,6 +397,195 @@ mod tests {
         fn takes_dyn_render(_: &dyn super::RootRender) {}
     }
 
+    #[test]
+    fn suspense_implements_render() {
+        #[allow(dead_code)]
+        fn takes_render<'a>(_: &dyn super::Render<'a>) {}
+
+        #[allow(dead_code)]
+        fn suspense_is_render<'a, 'state>(s: &super::Suspense<'a, 'state, ()>) {
+            takes_render(s);
+        }
+    }
+
+    /// A future that stays `Pending` until `resolve` is called on its
+    /// handle, at which point it wakes whichever waker last polled it.
+    /// Lets tests drive the exact `Pending -> wake -> Ready` sequence
+    /// `SuspenseState` is built to handle.
+    struct ManualFuture<T> {
+        shared: Rc<RefCell<ManualFutureShared<T>>>,
+    }
+
+    #[derive(Default)]
+    struct ManualFutureShared<T> {
+        value: Option<T>,
+        waker: Option<std::task::Waker>,
+    }
+
+    struct ManualFutureHandle<T> {
+        shared: Rc<RefCell<ManualFutureShared<T>>>,
+    }
+
+    impl<T> ManualFuture<T> {
+        fn new() -> (ManualFuture<T>, ManualFutureHandle<T>) {
+            let shared = Rc::new(RefCell::new(ManualFutureShared {
+                value: None,
+                waker: None,
+            }));
+            (
+                ManualFuture {
+                    shared: shared.clone(),
+                },
+                ManualFutureHandle { shared },
+            )
+        }
+    }
+
+    impl<T> std::future::Future for ManualFuture<T> {
+        type Output = T;
+
+        fn poll(
+            self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<T> {
+            let mut shared = self.shared.borrow_mut();
+            match shared.value.take() {
+                Some(value) => std::task::Poll::Ready(value),
+                None => {
+                    shared.waker = Some(cx.waker().clone());
+                    std::task::Poll::Pending
+                }
+            }
+        }
+    }
+
+    impl<T> ManualFutureHandle<T> {
+        /// Make the future ready with `value` and wake whoever is polling it.
+        fn resolve(&self, value: T) {
+            let waker = {
+                let mut shared = self.shared.borrow_mut();
+                shared.value = Some(value);
+                shared.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+
+        /// Wake the last-registered waker twice via two independent clones,
+        /// without resolving the future, to simulate a future that notifies
+        /// more than once for the same bit of progress.
+        fn wake_twice(&self) {
+            let waker = self.shared.borrow().waker.clone();
+            if let Some(waker) = waker {
+                let other = waker.clone();
+                waker.wake();
+                other.wake();
+            }
+        }
+    }
+
+    #[test]
+    fn suspense_state_pending_then_ready() {
+        let (future, handle) = ManualFuture::new();
+        let state = super::SuspenseState::new(future);
+        let schedules = Rc::new(Cell::new(0u32));
+        let schedule: Rc<dyn Fn()> = {
+            let schedules = schedules.clone();
+            Rc::new(move || schedules.set(schedules.get() + 1))
+        };
+
+        // Still pending: the fallback keeps showing and nothing is scheduled.
+        assert!(!state.poll(schedule.clone()));
+        assert!(state.resolved().is_none());
+        assert_eq!(schedules.get(), 0);
+
+        // The future resolving must wake the stored waker, scheduling
+        // exactly one re-render.
+        handle.resolve(42);
+        assert_eq!(schedules.get(), 1);
+
+        // The next render's poll observes the resolved value and caches it.
+        assert!(state.poll(schedule.clone()));
+        assert_eq!(*state.resolved(), Some(42));
+
+        // Further renders must not re-poll the (now dropped) future, nor
+        // schedule again.
+        assert!(state.poll(schedule));
+        assert_eq!(*state.resolved(), Some(42));
+        assert_eq!(schedules.get(), 1);
+    }
+
+    #[test]
+    fn suspense_state_schedules_once_even_if_woken_twice() {
+        let (future, handle) = ManualFuture::<u32>::new();
+        let state = super::SuspenseState::new(future);
+        let schedules = Rc::new(Cell::new(0u32));
+        let schedule: Rc<dyn Fn()> = {
+            let schedules = schedules.clone();
+            Rc::new(move || schedules.set(schedules.get() + 1))
+        };
+
+        assert!(!state.poll(schedule));
+        handle.wake_twice();
+        assert_eq!(
+            schedules.get(),
+            1,
+            "a single poll's waker must only ever schedule one re-render"
+        );
+    }
+
+    #[test]
+    fn suspense_instances_are_rebuilt_but_state_carries_forward() {
+        use crate::builder::text;
+        use crate::RenderContext;
+
+        // The persistent half: built once, the way it would live on a
+        // user's root model.
+        let (future, handle) = ManualFuture::<u32>::new();
+        let state = super::SuspenseState::new(future);
+        let schedules = Rc::new(Cell::new(0u32));
+
+        // Render pass 1: a throwaway `Suspense` wrapper, exactly like
+        // `Render::render` would build fresh every pass. Nothing has
+        // resolved yet, so it's not ready.
+        let first_pass = super::Suspense::new(
+            {
+                let schedules = schedules.clone();
+                move || schedules.set(schedules.get() + 1)
+            },
+            &state,
+            text("fallback"),
+            |_: &u32, _: &mut RenderContext<'_>| text("resolved"),
+        );
+        assert!(!first_pass.is_ready());
+        drop(first_pass);
+
+        // The future resolves independently of any `Suspense` wrapper,
+        // waking the waker that pass 1's poll stored.
+        handle.resolve(7);
+        assert_eq!(schedules.get(), 1);
+
+        // Render pass 2: a brand new `Suspense`, with no connection to
+        // `first_pass`, wrapping the same persistent `state`. It must see
+        // the value the old wrapper's poll resolved, not start over.
+        let second_pass = super::Suspense::new(
+            {
+                let schedules = schedules.clone();
+                move || schedules.set(schedules.get() + 1)
+            },
+            &state,
+            text("fallback"),
+            |_: &u32, _: &mut RenderContext<'_>| text("resolved"),
+        );
+        assert!(
+            second_pass.is_ready(),
+            "an independently constructed Suspense must observe progress \
+             made by a previous pass's wrapper via the shared SuspenseState"
+        );
+        assert_eq!(*state.resolved(), Some(7));
+    }
+
     #[test]
     fn render_bump_scoped_child() {
         use crate::{builder::*, bumpalo::collections::String, Node, Render, RenderContext};